@@ -1,19 +1,130 @@
+use std::cmp::Ordering;
 use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use base64::Engine;
 use iter_tools::Itertools;
 use priority_queue::PriorityQueue;
+use serde::{Deserialize, Serialize};
 use unicase::UniCase;
 
+mod shared;
+mod stopwords;
+
+pub use shared::SharedSsage;
+pub use stopwords::{Language, StopWords};
+
 type SsageString = UniCase<String>;
 type SsageQueue = PriorityQueue<SsageString, Weight>;
 
+/// Wraps a [`SsageString`] so it can be (de)serialized without losing its original
+/// casing; `UniCase` itself only implements `serde` traits in a way that would
+/// normalize the text, which would defeat `Display`-ing keywords back out later.
+#[derive(Debug, Clone)]
+struct SerdeUniCase(SsageString);
+
+impl Serialize for SerdeUniCase {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_ref())
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeUniCase {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(UniCase::new(String::deserialize(deserializer)?)))
+    }
+}
+
+/// The subset of [`Ssage`] that gets round-tripped by [`Ssage::serialize`] /
+/// [`Ssage::deserialize`]; the [`Clock`] is intentionally excluded and reset to a
+/// [`SystemClock`] on restore, since a trait object can't be persisted.
+#[derive(Serialize, Deserialize)]
+struct SerializedState {
+    messages: Vec<SerdeUniCase>,
+    keywords: Vec<(SerdeUniCase, Weight)>,
+    configuration: Configuration,
+    document_frequency: Vec<(SerdeUniCase, u32)>,
+}
+
+/// Errors returned by [`Ssage::deserialize`].
 #[derive(Debug)]
+pub enum SsageError {
+    /// The input wasn't valid base64.
+    Decode(base64::DecodeError),
+    /// The decoded bytes didn't contain a valid `Ssage` state.
+    Serde(bincode::Error),
+}
+
+impl fmt::Display for SsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(error) => write!(f, "invalid base64: {error}"),
+            Self::Serde(error) => write!(f, "invalid ssage state: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SsageError {}
+
+impl From<base64::DecodeError> for SsageError {
+    fn from(error: base64::DecodeError) -> Self {
+        Self::Decode(error)
+    }
+}
+
+impl From<bincode::Error> for SsageError {
+    fn from(error: bincode::Error) -> Self {
+        Self::Serde(error)
+    }
+}
+
+/// Injectable source of time, so the forward-decay math in [`Ssage`] can be driven
+/// deterministically from tests instead of the wall clock. Bound by `Send + Sync`
+/// so a [`Ssage`] (and in turn a [`SharedSsage`](crate::SharedSsage)) can be moved
+/// across and shared between threads.
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// Seconds elapsed since an arbitrary but monotonically increasing epoch.
+    fn now(&self) -> u64;
+}
+
+/// Default [`Clock`] backed by [`SystemTime`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Configuration {
     pub threshold: Weight,
     pub take_words_min: usize,
     pub take_words_max: usize,
     pub take_words_percentage: usize,
     pub min_word_length: usize,
+    /// Decay rate used by the forward-decaying keyword weights, applied as
+    /// `w * exp(-alpha * (now - timestamp))`. Higher values make stale topics
+    /// fade out faster.
+    pub alpha: f64,
+    /// Words that are never surfaced as keywords; empty by default. Use
+    /// [`StopWords::preset`] to opt into a built-in language list.
+    pub stopwords: StopWords,
+    /// When `true`, candidate keywords are scored by TF-IDF over the stored message
+    /// history instead of raw term frequency, so words that are common across
+    /// every message stop outranking distinctive ones. Off by default to keep the
+    /// existing ranking behavior.
+    pub use_tf_idf: bool,
+    /// Caps how many messages are retained. Once exceeded, the oldest message is
+    /// evicted from the front of the ring buffer and its contribution to the
+    /// keyword weights and document-frequency map is unwound. `None` (the
+    /// default) keeps every message forever.
+    pub max_messages: Option<usize>,
 }
 
 impl Configuration {
@@ -22,6 +133,7 @@ impl Configuration {
     const TAKE_WORDS_MAX: usize = 30;
     const TAKE_WORDS_PERCENTAGE: usize = 10;
     const MIN_WORD_LENGTH: usize = 4;
+    const ALPHA: f64 = 0.015;
 
     pub fn new() -> Self {
         Self::default()
@@ -36,6 +148,10 @@ impl Default for Configuration {
             take_words_max: Self::TAKE_WORDS_MAX,
             take_words_percentage: Self::TAKE_WORDS_PERCENTAGE,
             min_word_length: Self::MIN_WORD_LENGTH,
+            alpha: Self::ALPHA,
+            stopwords: StopWords::new(),
+            use_tf_idf: false,
+            max_messages: None,
         }
     }
 }
@@ -45,32 +161,126 @@ pub struct Ssage {
     messages: VecDeque<SsageString>,
     keywords: SsageQueue,
     configuration: Configuration,
+    clock: Box<dyn Clock>,
+    /// Number of stored messages that contain each word, kept incrementally so
+    /// TF-IDF scoring (see [`Configuration::use_tf_idf`]) is O(1) per term.
+    document_frequency: HashMap<SsageString, u32>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Weight {
-    w: u64,
+    w: f64,
+    /// Last time (per the configured [`Clock`]) this weight was touched.
+    timestamp: u64,
 }
 
 impl Weight {
     pub fn new(w: u64) -> Self {
-        Self { w }
+        Self {
+            w: w as f64,
+            timestamp: 0,
+        }
+    }
+
+    /// This weight's magnitude decayed forward from `self.timestamp` to `now`, via
+    /// `w * exp(-alpha * (now - timestamp))`, so a keyword left unreinforced fades
+    /// the longer it's been since it was last touched.
+    fn decayed(&self, now: u64, alpha: f64) -> f64 {
+        self.w * (-alpha * now.saturating_sub(self.timestamp) as f64).exp()
+    }
+}
+
+impl PartialEq for Weight {
+    fn eq(&self, other: &Self) -> bool {
+        self.w == other.w
+    }
+}
+
+impl Eq for Weight {}
+
+impl PartialOrd for Weight {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Weight {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.w.total_cmp(&other.w)
     }
 }
 
 impl Ssage {
-    const MIN_THRESHOLD: u64 = 1;
-    const MAX_THRESHOLD: u64 = 20;
-    const WEIGHT_INCREMENT: u64 = 1;
+    const MIN_THRESHOLD: f64 = 1.;
+    const MAX_THRESHOLD: f64 = 20.;
+    const WEIGHT_INCREMENT: f64 = 1.;
 
     pub fn new(configuration: Configuration) -> Self {
+        Self::with_clock(configuration, Box::new(SystemClock))
+    }
+
+    /// Same as [`Ssage::new`], but with an injectable [`Clock`] so callers (tests,
+    /// mainly) can drive the forward-decay math with deterministic instants.
+    pub fn with_clock(configuration: Configuration, clock: Box<dyn Clock>) -> Self {
         Self {
             messages: VecDeque::new(),
             keywords: SsageQueue::new(),
             configuration,
+            clock,
+            document_frequency: HashMap::new(),
         }
     }
 
+    /// Encodes the engine's full state (messages, keyword weights and
+    /// configuration) as a single ASCII-safe, base64-encoded blob that can be
+    /// dropped into a config file, a DB column or an HTTP body, and later restored
+    /// with [`Ssage::deserialize`].
+    pub fn serialize(&self) -> String {
+        let state = SerializedState {
+            messages: self.messages.iter().cloned().map(SerdeUniCase).collect(),
+            keywords: self
+                .keywords
+                .iter()
+                .map(|(word, weight)| (SerdeUniCase(word.clone()), *weight))
+                .collect(),
+            configuration: self.configuration.clone(),
+            document_frequency: self
+                .document_frequency
+                .iter()
+                .map(|(word, count)| (SerdeUniCase(word.clone()), *count))
+                .collect(),
+        };
+
+        let bytes = bincode::serialize(&state).expect("SerializedState always serializes");
+
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    }
+
+    /// Restores an [`Ssage`] previously persisted with [`Ssage::serialize`]. The
+    /// restored instance always uses a fresh [`SystemClock`]; a restored engine
+    /// cannot be given another [`Clock`].
+    pub fn deserialize(s: &str) -> Result<Self, SsageError> {
+        let bytes = base64::engine::general_purpose::STANDARD.decode(s)?;
+        let state: SerializedState = bincode::deserialize(&bytes)?;
+
+        let mut keywords = SsageQueue::new();
+        for (word, weight) in state.keywords {
+            keywords.push(word.0, weight);
+        }
+
+        Ok(Self {
+            messages: state.messages.into_iter().map(|word| word.0).collect(),
+            keywords,
+            configuration: state.configuration,
+            clock: Box::new(SystemClock),
+            document_frequency: state
+                .document_frequency
+                .into_iter()
+                .map(|(word, count)| (word.0, count))
+                .collect(),
+        })
+    }
+
     /// Example
     ///
     /// ```
@@ -84,6 +294,13 @@ impl Ssage {
     ///     println!("Output: {}", ssage.feed("just a message"));
     /// ```
     pub fn feed<S: AsRef<str>>(&mut self, message: S) -> String {
+        self.feed_words(message).join(" ")
+    }
+
+    /// Does the full work of [`Ssage::feed`] but stops short of joining the
+    /// selected keywords into a string, so [`SharedSsage`](crate::SharedSsage) can
+    /// release its lock before paying for the formatting.
+    pub(crate) fn feed_words<S: AsRef<str>>(&mut self, message: S) -> Vec<String> {
         let clean_message = message
             .as_ref()
             .chars()
@@ -95,17 +312,23 @@ impl Ssage {
             })
             .collect();
         let message = UniCase::new(clean_message);
-        let mut keywords = self.fetch_important_keywords(&message);
+        let (ranked, deltas) = self.fetch_important_keywords(&message);
 
-        let output = self.fetch(
-            &keywords,
+        let words = self.fetch(
+            &ranked,
             Some(message.len() * self.configuration.take_words_percentage / 100),
         );
 
         self.messages.push_back(message);
-        self.keywords.append(&mut keywords);
+        self.merge_keyword_weights(deltas);
+
+        if let Some(max_messages) = self.configuration.max_messages {
+            while self.messages.len() > max_messages {
+                self.evict_oldest_message();
+            }
+        }
 
-        output
+        words
     }
 
     /// Example
@@ -121,17 +344,39 @@ impl Ssage {
     ///     println!("Output: {}", ssage.feed_empty());
     /// ```
     pub fn feed_empty(&self) -> String {
+        self.feed_empty_words().join(" ")
+    }
+
+    /// Does the full work of [`Ssage::feed_empty`] but stops short of joining the
+    /// selected keywords into a string; see [`Ssage::feed_words`].
+    pub(crate) fn feed_empty_words(&self) -> Vec<String> {
         self.fetch(&self.keywords, Some(self.configuration.take_words_max))
     }
 
-    fn fetch(&self, keywords: &SsageQueue, words: Option<usize>) -> String {
-        let keywords = keywords
-            .clone()
-            .into_sorted_iter()
-            .filter(|(word, weight)| {
-                *weight >= self.configuration.threshold
+    /// Ranks `keywords` by their weight decayed to the current instant (so a
+    /// keyword that's been surfaced before but hasn't been touched in a while
+    /// doesn't outrank one that was just reinforced) rather than by raw stored
+    /// weight, which is only ever correct as of that weight's own `timestamp`.
+    /// Also re-checks the stopword list here rather than only at ingestion, so
+    /// a word added to the list after it was already stored stops surfacing
+    /// immediately instead of lingering until it decays below `threshold`.
+    fn fetch(&self, keywords: &SsageQueue, words: Option<usize>) -> Vec<String> {
+        let now = self.clock.now();
+        let alpha = self.configuration.alpha;
+        let threshold = self.configuration.threshold.w;
+
+        let mut keywords = keywords
+            .iter()
+            .filter_map(|(word, weight)| {
+                let decayed = weight.decayed(now, alpha);
+                (decayed >= threshold
                     && word.len() >= self.configuration.min_word_length
-            });
+                    && !self.configuration.stopwords.contains(word))
+                .then(|| (word.clone(), decayed))
+            })
+            .collect::<Vec<_>>();
+        keywords.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        let keywords = keywords.into_iter().map(|(word, _)| word);
 
         if let Some(mut words) = words {
             if words < self.configuration.min_word_length {
@@ -140,61 +385,137 @@ impl Ssage {
                 words = self.configuration.take_words_max;
             }
 
-            keywords.take(words).map(|(word, _)| word).join(" ")
+            keywords.take(words).map(|word| word.to_string()).collect()
         } else {
-            keywords.map(|(word, _)| word).join(" ")
+            keywords.map(|word| word.to_string()).collect()
         }
     }
 
     pub fn prioritize_keyword<S: AsRef<str>>(&mut self, keyword: S) -> bool {
+        let now = self.tick();
+
         Self::change_keyword_weight(
             &mut self.keywords,
             keyword,
             false,
-            Self::WEIGHT_INCREMENT as i64,
+            Self::WEIGHT_INCREMENT,
+            now,
+            self.configuration.alpha,
         )
     }
 
     pub fn trivialize_keyword<S: AsRef<str>>(&mut self, keyword: S) -> bool {
+        let now = self.tick();
+
         Self::change_keyword_weight(
             &mut self.keywords,
             keyword,
             false,
-            -(Self::WEIGHT_INCREMENT as i64),
+            -Self::WEIGHT_INCREMENT,
+            now,
+            self.configuration.alpha,
         )
     }
 
+    /// Adds a word to the stopword list so it never gets surfaced as a keyword.
+    pub fn add_stopword<S: AsRef<str>>(&mut self, word: S) {
+        self.configuration.stopwords.add(word);
+    }
+
+    /// Removes a word from the stopword list, if present.
+    pub fn remove_stopword<S: AsRef<str>>(&mut self, word: S) -> bool {
+        self.configuration.stopwords.remove(word)
+    }
+
+    /// Current time, per the configured [`Clock`].
+    fn tick(&self) -> u64 {
+        self.clock.now()
+    }
+
+    /// Merges a message's freshly scored keywords into `self.keywords`, folding
+    /// each word's contribution into any existing entry (decayed to `contribution`'s
+    /// own timestamp first) instead of discarding it: `PriorityQueue::append`
+    /// drops either side wholesale on a duplicate key, which silently stopped
+    /// updating a word's weight/timestamp as soon as `self.keywords` outgrew a
+    /// single message's word count.
+    fn merge_keyword_weights(&mut self, contributions: SsageQueue) {
+        let alpha = self.configuration.alpha;
+
+        for (word, weight) in contributions {
+            Self::change_keyword_weight(
+                &mut self.keywords,
+                word,
+                true,
+                weight.w,
+                weight.timestamp,
+                alpha,
+            );
+        }
+    }
+
+    /// Pops the oldest stored message once [`Configuration::max_messages`] is
+    /// exceeded, unwinding its contribution to the keyword weights and the
+    /// document-frequency map so the model reflects only the retained window.
+    fn evict_oldest_message(&mut self) {
+        let Some(message) = self.messages.pop_front() else {
+            return;
+        };
+
+        let now = self.tick();
+        let alpha = self.configuration.alpha;
+
+        for word in message.split_whitespace().unique() {
+            let key = UniCase::new(word.into());
+
+            if let Some(count) = self.document_frequency.get_mut(&key) {
+                *count -= 1;
+                if *count == 0 {
+                    self.document_frequency.remove(&key);
+                }
+            }
+
+            Self::change_keyword_weight(
+                &mut self.keywords,
+                word,
+                false,
+                -Self::WEIGHT_INCREMENT,
+                now,
+                alpha,
+            );
+        }
+    }
+
+    /// Decays `keyword`'s existing weight to `now` (see [`Weight::decayed`]) and
+    /// folds `increment` into it, clamped to `[MIN_THRESHOLD, MAX_THRESHOLD]`.
+    /// Decaying the existing weight before adding, rather than inflating
+    /// `increment` by a growing scale factor, keeps the result bounded by the
+    /// clamp regardless of how long it's been since the keyword was last touched.
     fn change_keyword_weight<S: AsRef<str>>(
         keywords: &mut SsageQueue,
         keyword: S,
         insert_if_not_exists: bool,
-        increment: i64,
+        increment: f64,
+        now: u64,
+        alpha: f64,
     ) -> bool {
         let key = UniCase::new(keyword.as_ref().into());
-        if let Some(weight) = keywords.get_priority(&key) {
-            let mut weight = weight.clone();
-
-            if increment >= 0 {
-                weight.w += increment.abs() as u64;
-            } else {
-                weight.w -= increment.abs() as u64;
-            }
 
-            if weight.w < Self::MIN_THRESHOLD {
-                weight.w = Self::MIN_THRESHOLD;
-            } else if weight.w > Self::MAX_THRESHOLD {
-                weight.w = Self::MAX_THRESHOLD;
-            }
+        if let Some(weight) = keywords.get_priority(&key) {
+            let decayed = weight.decayed(now, alpha);
+            let weight = Weight {
+                w: (decayed + increment).clamp(Self::MIN_THRESHOLD, Self::MAX_THRESHOLD),
+                timestamp: now,
+            };
 
             keywords.change_priority(&key, weight).is_some()
         } else if insert_if_not_exists {
-            let weight = if increment >= 0 {
-                increment.abs() as u64
+            let w = if increment >= 0. {
+                increment.clamp(Self::MIN_THRESHOLD, Self::MAX_THRESHOLD)
             } else {
                 Self::MIN_THRESHOLD
             };
 
-            keywords.push(key, Weight::new(weight));
+            keywords.push(key, Weight { w, timestamp: now });
 
             true
         } else {
@@ -202,7 +523,33 @@ impl Ssage {
         }
     }
 
-    fn fetch_important_keywords(&mut self, message: &SsageString) -> SsageQueue {
+    /// TF-IDF score for `word`: raw term frequency within the incoming message,
+    /// scaled down by how many of the already-stored messages also contain it, so
+    /// words common across the whole corpus stop outranking distinctive ones.
+    fn tfidf_score(&self, word: &str, term_frequency: u32) -> f64 {
+        let document_count = self.messages.len() as f64;
+        let document_frequency = self
+            .document_frequency
+            .get(&UniCase::new(word.into()))
+            .copied()
+            .unwrap_or(0) as f64;
+
+        term_frequency as f64 * (document_count / (1. + document_frequency)).ln()
+    }
+
+    /// Scores this message's words twice, for two different purposes: `ranked`
+    /// folds each word's existing persisted weight in on top of this message's
+    /// own score, purely so the words are comparable for picking this call's
+    /// output; `deltas` is this message's own bounded contribution alone, with
+    /// no history baked in. [`Ssage::merge_keyword_weights`] needs `deltas` -
+    /// folding `ranked` into the persisted weight instead would add that same
+    /// history on top of itself again every time a word recurs. With
+    /// [`Configuration::use_tf_idf`] set, `ranked` is the TF-IDF score alone and
+    /// equals `deltas`.
+    fn fetch_important_keywords(&mut self, message: &SsageString) -> (SsageQueue, SsageQueue) {
+        let now = self.tick();
+        let alpha = self.configuration.alpha;
+
         let words: Vec<&str> = message.split_whitespace().collect();
 
         let mut scanned_words = HashMap::new();
@@ -210,32 +557,71 @@ impl Ssage {
             scanned_words.insert(word, scanned_words.get(&word).unwrap_or(&0) + 1);
         });
 
-        let mut weighted_words = SsageQueue::new();
+        let mut ranked = SsageQueue::new();
+        let mut deltas = SsageQueue::new();
+
+        if self.configuration.use_tf_idf {
+            // TF-IDF ranks by this message's own score alone, so it doesn't reuse
+            // the two-pass count-then-cancel trick below: the persisted weight grows
+            // with every occurrence, and adding it back would let recurring words
+            // outrank distinctive ones again. `ranked` and `deltas` are the same.
+            scanned_words.iter().for_each(|(word, term_frequency)| {
+                if self.configuration.stopwords.contains(**word) {
+                    return;
+                }
+
+                let score = self.tfidf_score(word, *term_frequency as u32);
+                let _ = Self::change_keyword_weight(&mut deltas, word, true, score, now, alpha);
+            });
 
-        words.iter().for_each(|word| {
-            let _ = Self::change_keyword_weight(
-                &mut weighted_words,
-                word,
-                true,
-                Self::WEIGHT_INCREMENT as i64,
-            );
-        });
+            ranked = deltas.clone();
+        } else {
+            words.iter().for_each(|word| {
+                if self.configuration.stopwords.contains(word) {
+                    return;
+                }
+
+                let _ = Self::change_keyword_weight(
+                    &mut deltas,
+                    word,
+                    true,
+                    Self::WEIGHT_INCREMENT,
+                    now,
+                    alpha,
+                );
+            });
+
+            ranked = deltas.clone();
+
+            scanned_words.keys().for_each(|word| {
+                if self.configuration.stopwords.contains(**word) {
+                    return;
+                }
+
+                let key = UniCase::new((**word).into());
+                let _ = Self::change_keyword_weight(
+                    &mut ranked,
+                    word,
+                    true,
+                    if let Some(weight) = self.keywords.get_priority(&key) {
+                        weight.w
+                    } else {
+                        -Self::WEIGHT_INCREMENT
+                    },
+                    now,
+                    alpha,
+                );
+            });
+        }
 
         scanned_words.keys().for_each(|word| {
-            let key = UniCase::new((**word).into());
-            let _ = Self::change_keyword_weight(
-                &mut weighted_words,
-                word,
-                true,
-                if let Some(weight) = self.keywords.get_priority(&key) {
-                    weight.w as i64
-                } else {
-                    -(Self::WEIGHT_INCREMENT as i64)
-                },
-            );
+            *self
+                .document_frequency
+                .entry(UniCase::new((**word).into()))
+                .or_insert(0) += 1;
         });
 
-        weighted_words
+        (ranked, deltas)
     }
 }
 
@@ -275,4 +661,209 @@ mod test {
 
         assert_eq!(ssage.feed("just a message"), "message just");
     }
+
+    impl Clock for std::sync::Arc<std::sync::atomic::AtomicU64> {
+        fn now(&self) -> u64 {
+            self.load(std::sync::atomic::Ordering::Relaxed)
+        }
+    }
+
+    #[test]
+    pub fn test_recent_reinforcement_outweighs_a_stale_one() {
+        let clock = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+
+        let mut ssage = Ssage::with_clock(
+            Configuration {
+                alpha: 0.05,
+                ..Default::default()
+            },
+            Box::new(clock.clone()),
+        );
+
+        let _ = ssage.feed("alpha beta");
+        ssage.prioritize_keyword("alpha");
+
+        clock.fetch_add(10, std::sync::atomic::Ordering::Relaxed);
+        ssage.prioritize_keyword("beta");
+
+        assert_eq!(ssage.feed_empty(), "beta alpha");
+    }
+
+    #[test]
+    pub fn test_serialize_roundtrip_preserves_state_and_casing() {
+        let mut ssage = Ssage::new(Default::default());
+
+        let _ = ssage.feed("hi! how are you Mate?");
+        let _ = ssage.feed("this is just a sample message.");
+
+        let blob = ssage.serialize();
+        assert!(blob.is_ascii());
+
+        let restored = Ssage::deserialize(&blob).expect("blob should round-trip");
+
+        assert_eq!(restored.feed_empty(), ssage.feed_empty());
+        assert!(restored
+            .messages
+            .iter()
+            .any(|message| message.as_ref() == "hi  how are you Mate "));
+    }
+
+    #[test]
+    pub fn test_stopwords_preset_filters_common_words() {
+        let mut ssage = Ssage::new(Configuration {
+            stopwords: StopWords::preset(Language::English),
+            ..Default::default()
+        });
+
+        let _ = ssage.feed("hi! how are you mate?");
+        let _ = ssage.feed("this is just a sample message.");
+
+        let output = ssage.feed_empty();
+        let words = output.split_whitespace().collect::<Vec<_>>();
+
+        assert!(words.contains(&"mate"));
+        assert!(words.contains(&"message"));
+        assert!(words.contains(&"sample"));
+        assert!(!words.contains(&"this"));
+        assert!(!words.contains(&"just"));
+    }
+
+    #[test]
+    pub fn test_add_and_remove_stopword_at_runtime() {
+        let mut ssage = Ssage::new(Default::default());
+        ssage.add_stopword("sample");
+
+        let output = ssage.feed("this is just a sample message.");
+        assert!(!output.split_whitespace().any(|word| word == "sample"));
+
+        ssage.remove_stopword("sample");
+
+        let output = ssage.feed("another sample message");
+        assert!(output.split_whitespace().any(|word| word == "sample"));
+    }
+
+    #[test]
+    pub fn test_stopword_added_after_the_fact_stops_surfacing_stored_keyword() {
+        let mut ssage = Ssage::new(Default::default());
+
+        let output = ssage.feed("sample message");
+        assert!(output.split_whitespace().any(|word| word == "sample"));
+
+        ssage.add_stopword("sample");
+
+        let output = ssage.feed_empty();
+        assert!(!output.split_whitespace().any(|word| word == "sample"));
+    }
+
+    #[test]
+    pub fn test_tfidf_ranks_distinctive_words_above_recurring_ones() {
+        let mut ssage = Ssage::new(Configuration {
+            use_tf_idf: true,
+            ..Default::default()
+        });
+
+        for filler in ["apple", "banana", "cherry", "date"] {
+            let _ = ssage.feed(format!("common {filler}"));
+        }
+
+        let output = ssage.feed("common rare");
+        let words = output.split_whitespace().collect::<Vec<_>>();
+
+        let common_rank = words
+            .iter()
+            .position(|word| *word == "common")
+            .expect("common should still appear");
+        let rare_rank = words
+            .iter()
+            .position(|word| *word == "rare")
+            .expect("rare should appear");
+
+        assert!(
+            rare_rank < common_rank,
+            "a word distinctive to one message should outrank one recurring in every message: {output}"
+        );
+    }
+
+    #[test]
+    pub fn test_max_messages_evicts_the_oldest_message() {
+        let mut ssage = Ssage::new(Configuration {
+            max_messages: Some(2),
+            ..Default::default()
+        });
+
+        let _ = ssage.feed("hi! how are you mate?");
+        assert_eq!(ssage.messages.len(), 1);
+
+        let _ = ssage.feed("this is just a sample message.");
+        assert_eq!(ssage.messages.len(), 2);
+
+        let _ = ssage.feed("another distinct topic entirely");
+        assert_eq!(ssage.messages.len(), 2);
+
+        assert!(!ssage
+            .messages
+            .iter()
+            .any(|message| message.as_ref() == "hi  how are you mate "));
+        assert!(!ssage
+            .document_frequency
+            .contains_key(&UniCase::new("mate".to_string())));
+    }
+
+    #[test]
+    pub fn test_eviction_does_not_wipe_weight_still_backed_by_another_message() {
+        let clock = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+
+        let mut ssage = Ssage::with_clock(
+            Configuration {
+                max_messages: Some(2),
+                ..Default::default()
+            },
+            Box::new(clock.clone()),
+        );
+
+        let _ = ssage.feed("hi there mate");
+        for _ in 0..10 {
+            ssage.prioritize_keyword("mate");
+        }
+
+        let _ = ssage.feed("still here mate");
+        assert_eq!(ssage.messages.len(), 2);
+
+        clock.fetch_add(10, std::sync::atomic::Ordering::Relaxed);
+
+        // Evicts "hi there mate", but "mate" is still backed by "still here mate".
+        let _ = ssage.feed("a third distinct message");
+        assert_eq!(ssage.messages.len(), 2);
+
+        assert!(ssage
+            .document_frequency
+            .contains_key(&UniCase::new("mate".to_string())));
+        assert!(ssage
+            .feed_empty()
+            .split_whitespace()
+            .any(|word| word == "mate"));
+    }
+
+    #[test]
+    pub fn test_shared_ssage_serves_concurrent_feeds() {
+        let shared = std::sync::Arc::new(SharedSsage::new(Default::default()));
+
+        let handles = (0..4)
+            .map(|i| {
+                let shared = shared.clone();
+                std::thread::spawn(move || shared.feed(format!("hi! how are you mate number {i}?")))
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle.join().expect("feeder thread should not panic");
+        }
+
+        shared.prioritize_keyword("mate");
+
+        assert!(shared
+            .feed_empty()
+            .split_whitespace()
+            .any(|word| word == "mate"));
+    }
 }
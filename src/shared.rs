@@ -0,0 +1,47 @@
+use std::sync::Mutex;
+
+use crate::{Configuration, Ssage};
+
+/// A [`Ssage`] behind a lock, so many request handlers can feed and query one
+/// trained instance concurrently. `feed`/`feed_empty` only hold the lock long
+/// enough to update the engine and clone out the selected keywords; joining them
+/// into the returned string happens after the lock is released, so formatting one
+/// caller's output never blocks another caller's feed.
+#[derive(Debug)]
+pub struct SharedSsage(Mutex<Ssage>);
+
+impl SharedSsage {
+    pub fn new(configuration: Configuration) -> Self {
+        Self(Mutex::new(Ssage::new(configuration)))
+    }
+
+    /// See [`Ssage::feed`].
+    pub fn feed<S: AsRef<str>>(&self, message: S) -> String {
+        let words = self
+            .0
+            .lock()
+            .expect("SharedSsage mutex poisoned")
+            .feed_words(message);
+
+        words.join(" ")
+    }
+
+    /// See [`Ssage::feed_empty`].
+    pub fn feed_empty(&self) -> String {
+        let words = self
+            .0
+            .lock()
+            .expect("SharedSsage mutex poisoned")
+            .feed_empty_words();
+
+        words.join(" ")
+    }
+
+    /// See [`Ssage::prioritize_keyword`].
+    pub fn prioritize_keyword<S: AsRef<str>>(&self, keyword: S) -> bool {
+        self.0
+            .lock()
+            .expect("SharedSsage mutex poisoned")
+            .prioritize_keyword(keyword)
+    }
+}
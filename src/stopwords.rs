@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use unicase::UniCase;
+
+use crate::SsageString;
+
+/// Built-in stopword presets selectable on [`crate::Configuration`], one embedded
+/// word list per supported language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+}
+
+impl Language {
+    fn word_list(self) -> &'static [&'static str] {
+        match self {
+            Self::English => ENGLISH,
+        }
+    }
+}
+
+/// A set of words that [`crate::Ssage::feed`] should never surface as a keyword,
+/// regardless of how often they occur.
+///
+/// Starts out empty; opt into a built-in list with [`StopWords::preset`], or build
+/// a custom one word by word with [`StopWords::add`].
+#[derive(Debug, Clone, Default)]
+pub struct StopWords(HashSet<SsageString>);
+
+impl StopWords {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a `StopWords` from one of the embedded per-language word lists.
+    pub fn preset(language: Language) -> Self {
+        let mut stopwords = Self::new();
+        for word in language.word_list() {
+            stopwords.add(word);
+        }
+
+        stopwords
+    }
+
+    pub fn add<S: AsRef<str>>(&mut self, word: S) {
+        self.0.insert(UniCase::new(word.as_ref().into()));
+    }
+
+    pub fn remove<S: AsRef<str>>(&mut self, word: S) -> bool {
+        self.0.remove(&UniCase::new(word.as_ref().into()))
+    }
+
+    pub fn contains<S: AsRef<str>>(&self, word: S) -> bool {
+        self.0.contains(&UniCase::new(word.as_ref().into()))
+    }
+}
+
+// `UniCase<String>` doesn't carry a serde impl that preserves the original casing
+// (see `SerdeUniCase` in `lib.rs`), so `StopWords` is (de)serialized through a
+// plain `Vec<String>` instead of deriving on the `HashSet` directly.
+impl Serialize for StopWords {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0
+            .iter()
+            .map(|word| word.as_ref())
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StopWords {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut stopwords = Self::new();
+        for word in Vec::<String>::deserialize(deserializer)? {
+            stopwords.add(word);
+        }
+
+        Ok(stopwords)
+    }
+}
+
+const ENGLISH: &[&str] = &[
+    "a", "about", "above", "after", "again", "against", "all", "am", "an", "and", "any", "are",
+    "aren't", "as", "at", "be", "because", "been", "before", "being", "below", "between", "both",
+    "but", "by", "can't", "cannot", "could", "couldn't", "did", "didn't", "do", "does", "doesn't",
+    "doing", "don't", "down", "during", "each", "few", "for", "from", "further", "had", "hadn't",
+    "has", "hasn't", "have", "haven't", "having", "he", "he'd", "he'll", "he's", "her", "here",
+    "here's", "hers", "herself", "him", "himself", "his", "how", "how's", "i", "i'd", "i'll",
+    "i'm", "i've", "if", "in", "into", "is", "isn't", "it", "it's", "its", "itself", "just",
+    "let's", "me", "more", "most", "mustn't", "my", "myself", "no", "nor", "not", "of", "off",
+    "on", "once", "only", "or", "other", "ought", "our", "ours", "ourselves", "out", "over",
+    "own", "same", "shan't", "she", "she'd", "she'll", "she's", "should", "shouldn't", "so",
+    "some", "such", "than", "that", "that's", "the", "their", "theirs", "them", "themselves",
+    "then", "there", "there's", "these", "they", "they'd", "they'll", "they're", "they've",
+    "this", "those", "through", "to", "too", "under", "until", "up", "very", "was", "wasn't",
+    "we", "we'd", "we'll", "we're", "we've", "were", "weren't", "what", "what's", "when",
+    "when's", "where", "where's", "which", "while", "who", "who's", "whom", "why", "why's",
+    "with", "won't", "would", "wouldn't", "you", "you'd", "you'll", "you're", "you've", "your",
+    "yours", "yourself", "yourselves",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_preset_is_case_insensitive() {
+        let stopwords = StopWords::preset(Language::English);
+
+        assert!(stopwords.contains("just"));
+        assert!(stopwords.contains("JUST"));
+        assert!(!stopwords.contains("message"));
+    }
+
+    #[test]
+    pub fn test_add_and_remove_custom_words() {
+        let mut stopwords = StopWords::new();
+        assert!(!stopwords.contains("widget"));
+
+        stopwords.add("widget");
+        assert!(stopwords.contains("widget"));
+
+        assert!(stopwords.remove("widget"));
+        assert!(!stopwords.contains("widget"));
+    }
+}